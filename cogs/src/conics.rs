@@ -0,0 +1,172 @@
+/*
+ * Copyright 2025 Kurt Motekew
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use nalgebra as na;
+
+/// Eccentricities within this tolerance of unity are treated as the
+/// unsupported parabolic case.
+const PARABOLIC_TOL: f64 = 1.0e-10;
+
+/**
+ * Classical (Keplerian) orbital elements describing a two-body conic,
+ * mirroring the element set consumed by SPICE's conics routine.  Angles
+ * are in radians and the time fields share a common epoch scale.
+ *
+ * @author  Kurt Motekew  2025
+ */
+#[derive(Copy, Clone)]
+pub struct Elements {
+    pub rp: f64,                                 // Periapsis radius
+    pub ecc: f64,                                // Eccentricity, ecc >= 0
+    pub inc: f64,                                // Inclination
+    pub lnode: f64,                              // Longitude of ascending node
+    pub argp: f64,                               // Argument of periapsis
+    pub m0: f64,                                 // Mean anomaly at t0
+    pub t0: f64,                                 // Epoch of m0
+}
+
+/**
+ * Propagates a set of classical orbital elements to a Cartesian
+ * position/velocity state at the requested epoch.  The ellipse and the
+ * hyperbola share the perifocal formulation; the eccentric anomaly (or
+ * its hyperbolic analogue) is recovered with a Newton iteration seeded at
+ * the mean anomaly, after which the perifocal state is rotated into the
+ * inertial frame with the 3-1-3 sequence Rz(lnode)*Rx(inc)*Rz(argp).
+ *
+ * The exactly parabolic case (ecc == 1) has no finite semimajor axis and
+ * is not supported; eccentricities within PARABOLIC_TOL of unity are
+ * rejected with a panic rather than returning an inf/NaN state.
+ *
+ * @param  elts  Classical orbital elements, with ecc clear of unity
+ * @param  mu    Gravitational parameter of the central body
+ * @param  dt    Epoch at which to evaluate the state (same scale as t0)
+ *
+ * @return  (position, velocity) in the inertial frame
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn state_from_elements(elts: &Elements, mu: f64,
+                           dt: f64) -> (na::SMatrix<f64, 3, 1>,
+                                        na::SMatrix<f64, 3, 1>) {
+    let ecc = elts.ecc;
+    assert!((ecc - 1.0).abs() > PARABOLIC_TOL,
+            "state_from_elements: near-parabolic eccentricity unsupported");
+    let a = elts.rp/(1.0 - ecc);
+    let n = (mu/a.abs().powi(3)).sqrt();
+    let big_m = elts.m0 + n*(dt - elts.t0);
+
+    // Perifocal position and velocity
+    let (rpf, vpf) = if ecc < 1.0 {
+        // Solve Kepler's equation M = E - ecc*sin(E) for the eccentric
+        // anomaly, then build the perifocal state.
+        let ea = solve_kepler(big_m, ecc);
+        let v = 2.0*(((1.0 + ecc).sqrt()*(0.5*ea).sin())
+                         .atan2((1.0 - ecc).sqrt()*(0.5*ea).cos()));
+        let r = a*(1.0 - ecc*ea.cos());
+        let coef = (mu*a).sqrt()/r;
+        (na::matrix![r*v.cos() ; r*v.sin() ; 0.0],
+         na::matrix![-coef*ea.sin() ;
+                      coef*(1.0 - ecc*ecc).sqrt()*ea.cos() ;
+                      0.0])
+    } else {
+        // Hyperbolic analogue: M = ecc*sinh(H) - H.
+        let ha = solve_kepler_hyperbolic(big_m, ecc);
+        let v = 2.0*(((ecc + 1.0).sqrt()*(0.5*ha).sinh())
+                         .atan2((ecc - 1.0).sqrt()*(0.5*ha).cosh()));
+        let r = a*(1.0 - ecc*ha.cosh());
+        let coef = (-mu*a).sqrt()/r;
+        (na::matrix![r*v.cos() ; r*v.sin() ; 0.0],
+         na::matrix![-coef*ha.sinh() ;
+                      coef*(ecc*ecc - 1.0).sqrt()*ha.cosh() ;
+                      0.0])
+    };
+
+    let rot = rz(elts.lnode)*rx(elts.inc)*rz(elts.argp);
+    (rot*rpf, rot*vpf)
+}
+
+/*
+ * Newton iteration for the eccentric anomaly given mean anomaly and
+ * eccentricity, seeded at E = M.
+ */
+fn solve_kepler(big_m: f64, ecc: f64) -> f64 {
+    let mut ea = big_m;
+    for _ in 0..100 {
+        let dea = (ea - ecc*ea.sin() - big_m)/(1.0 - ecc*ea.cos());
+        ea -= dea;
+        if dea.abs() < 1.0e-14 {
+            break;
+        }
+    }
+    ea
+}
+
+/*
+ * Newton iteration for the hyperbolic anomaly given mean anomaly and
+ * eccentricity, seeded at H = M.
+ */
+fn solve_kepler_hyperbolic(big_m: f64, ecc: f64) -> f64 {
+    let mut ha = big_m;
+    for _ in 0..100 {
+        let dha = (ecc*ha.sinh() - ha - big_m)/(ecc*ha.cosh() - 1.0);
+        ha -= dha;
+        if dha.abs() < 1.0e-14 {
+            break;
+        }
+    }
+    ha
+}
+
+/*
+ * Right handed rotation about the z-axis
+ */
+fn rz(a: f64) -> na::SMatrix<f64, 3, 3> {
+    let c = a.cos();
+    let s = a.sin();
+    na::matrix![  c , -s , 0.0 ;
+                  s ,  c , 0.0 ;
+                0.0 , 0.0 , 1.0]
+}
+
+/*
+ * Right handed rotation about the x-axis
+ */
+fn rx(a: f64) -> na::SMatrix<f64, 3, 3> {
+    let c = a.cos();
+    let s = a.sin();
+    na::matrix![1.0 , 0.0 , 0.0 ;
+                0.0 ,   c , -s  ;
+                0.0 ,   s ,   c ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Propagates a circular equatorial orbit a quarter period and checks
+     * that the state rotated by 90 degrees with constant radius and speed.
+     */
+    #[test]
+    fn circular_quarter_period() {
+        let mu = 3.986004418e14;
+        let r = 7.0e6;
+        let elts = Elements {
+            rp: r, ecc: 0.0, inc: 0.0, lnode: 0.0, argp: 0.0, m0: 0.0, t0: 0.0,
+        };
+        let n = (mu/r.powi(3)).sqrt();
+        let dt = 0.25*(2.0*std::f64::consts::PI/n);
+
+        let (pos, vel) = state_from_elements(&elts, mu, dt);
+
+        assert!((pos.norm() - r).abs() < 1.0e-3);
+        assert!((vel.norm() - (mu/r).sqrt()).abs() < 1.0e-6);
+        // A quarter revolution from +x should land near +y.
+        assert!(pos[0].abs() < 1.0e-2  &&  (pos[1] - r).abs() < 1.0e-3);
+    }
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright 2025 Kurt Motekew
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use nalgebra as na;
+
+/*
+ * Computes the intersection of a ray with a triaxial ellipsoid centered
+ * at the origin.  The ellipsoid is mapped to a unit sphere by scaling
+ * every vector by the reciprocal of the corresponding axis length, the
+ * same quadratic solved by unit_circle::intersect is applied in that
+ * space, and the nearest intersection ahead of the position is mapped
+ * back onto the ellipsoid.  This lets an OblateSpheroid be used for
+ * line-of-sight and tangent-point computations against a planet surface.
+ *
+ * @param  pos   Ray origin, Cartesian coordinates
+ * @param  pnt   Ray pointing vector
+ * @param  axes  Ellipsoid semi-axis lengths along x, y, and z
+ *
+ * @return   Some(intersection point on the ellipsoid) for the nearest
+ *           positive root, otherwise None
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn intersect_ellipsoid(pos: &na::SMatrix<f64, 3, 1>,
+                           pnt: &na::SMatrix<f64, 3, 1>,
+                           axes: &na::SMatrix<f64, 3, 1>)
+                                      -> Option<na::SMatrix<f64, 3, 1>> {
+    // Map the ellipsoid to a unit sphere
+    let pos_s = na::matrix![pos[0]/axes[0] ;
+                            pos[1]/axes[1] ;
+                            pos[2]/axes[2]];
+    let pnt_s = na::matrix![pnt[0]/axes[0] ;
+                            pnt[1]/axes[1] ;
+                            pnt[2]/axes[2]];
+    let pnt_hat = pnt_s.normalize();
+
+    let alpha = pnt_hat.dot(&pnt_hat);
+    let beta = pos_s.dot(&pnt_hat);
+    let gamma = pos_s.dot(&pos_s);
+
+    let d = beta*beta - alpha*(gamma - 1.0);
+    if d < 0.0 {
+        return None;
+    }
+
+    // Nearest root ahead of the origin
+    let sqrt_d = d.sqrt();
+    let s1 = -(beta + sqrt_d)/alpha;
+    let s2 = -(beta - sqrt_d)/alpha;
+    let s = if s1 > 0.0 {
+        s1
+    } else if s2 > 0.0 {
+        s2
+    } else {
+        return None;
+    };
+
+    // Map the hit point back onto the ellipsoid
+    let hit = pos_s + s*pnt_hat;
+    Some(na::matrix![hit[0]*axes[0] ;
+                     hit[1]*axes[1] ;
+                     hit[2]*axes[2]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ellipsoid_intersect() {
+        let axes = na::matrix![2.0 ; 2.0 ; 1.0];
+        // Looking back at the ellipsoid from outside along -x
+        let pos = na::matrix![5.0 ; 0.0 ; 0.0];
+        let pnt = na::matrix![-1.0 ; 0.0 ; 0.0];
+        let hit = intersect_ellipsoid(&pos, &pnt, &axes)
+            .expect("ray hits ellipsoid");
+        // Nearest hit is the +x tip at the semimajor axis
+        assert!((hit[0] - 2.0).abs() < 1.0e-12);
+        assert!(hit[1].abs() < 1.0e-12  &&  hit[2].abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn ellipsoid_miss() {
+        let axes = na::matrix![2.0 ; 2.0 ; 1.0];
+        let pos = na::matrix![5.0 ; 0.0 ; 0.0];
+        let pnt = na::matrix![0.0 ; 1.0 ; 0.0];
+        assert!(intersect_ellipsoid(&pos, &pnt, &axes).is_none());
+    }
+}
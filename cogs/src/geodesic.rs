@@ -0,0 +1,349 @@
+/*
+ * Copyright 2025 Kurt Motekew
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use nalgebra as na;
+
+/**
+ * Solves the inverse geodesic problem on the reference ellipsoid with
+ * Vincenty's iteration: given two geodetic locations it returns the
+ * surface distance along the geodesic together with the forward and
+ * reverse azimuths.  Near-antipodal geometry converges slowly (or not at
+ * all); the iteration is capped and reported as an error in that case.
+ *
+ * @param  lat1  Latitude of the first point, radians
+ * @param  lon1  Longitude of the first point, radians
+ * @param  lat2  Latitude of the second point, radians
+ * @param  lon2  Longitude of the second point, radians
+ * @param  a     Ellipsoid semimajor axis
+ * @param  f     Ellipsoid flattening
+ *
+ * @return  Ok:  (distance, forward azimuth, reverse azimuth), the reverse
+ *               azimuth being the azimuth of the geodesic at the second
+ *               point in the direction of travel
+ *          Err: String when the near-antipodal solution fails to converge
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn inverse(lat1: f64, lon1: f64, lat2: f64, lon2: f64,
+               a: f64, f: f64) -> Result<(f64, f64, f64), String> {
+    let b = a*(1.0 - f);
+    let big_l = lon2 - lon1;
+
+    let u1 = ((1.0 - f)*lat1.tan()).atan();
+    let u2 = ((1.0 - f)*lat2.tan()).atan();
+    let (su1, cu1) = u1.sin_cos();
+    let (su2, cu2) = u2.sin_cos();
+
+    let mut lambda = big_l;
+    for _ in 0..200 {
+        let (sl, cl) = lambda.sin_cos();
+        let sin_sigma = ((cu2*sl).powi(2) +
+                         (cu1*su2 - su1*cu2*cl).powi(2)).sqrt();
+        // Coincident points
+        if sin_sigma == 0.0 {
+            return Ok((0.0, 0.0, 0.0));
+        }
+        let cos_sigma = su1*su2 + cu1*cu2*cl;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cu1*cu2*sl/sin_sigma;
+        let cos2_alpha = 1.0 - sin_alpha*sin_alpha;
+        // Equatorial line leaves cos(2*sigma_m) undefined; set to zero
+        let cos_2sigma_m = if cos2_alpha == 0.0 {
+            0.0
+        } else {
+            cos_sigma - 2.0*su1*su2/cos2_alpha
+        };
+        let c = f/16.0*cos2_alpha*(4.0 + f*(4.0 - 3.0*cos2_alpha));
+        let lambda_prev = lambda;
+        lambda = big_l + (1.0 - c)*f*sin_alpha*
+            (sigma + c*sin_sigma*(cos_2sigma_m +
+             c*cos_sigma*(-1.0 + 2.0*cos_2sigma_m*cos_2sigma_m)));
+        if (lambda - lambda_prev).abs() < 1.0e-12 {
+            let u2sq = cos2_alpha*(a*a - b*b)/(b*b);
+            let big_a = 1.0 + u2sq/16384.0*
+                (4096.0 + u2sq*(-768.0 + u2sq*(320.0 - 175.0*u2sq)));
+            let big_b = u2sq/1024.0*
+                (256.0 + u2sq*(-128.0 + u2sq*(74.0 - 47.0*u2sq)));
+            let delta_sigma = big_b*sin_sigma*(cos_2sigma_m +
+                0.25*big_b*(cos_sigma*(-1.0 + 2.0*cos_2sigma_m*cos_2sigma_m) -
+                big_b/6.0*cos_2sigma_m*(-3.0 + 4.0*sin_sigma*sin_sigma)*
+                (-3.0 + 4.0*cos_2sigma_m*cos_2sigma_m)));
+            let s = b*big_a*(sigma - delta_sigma);
+            let fwd_az = (cu2*sl).atan2(cu1*su2 - su1*cu2*cl);
+            let rev_az = (cu1*sl).atan2(-su1*cu2 + cu1*su2*cl);
+            return Ok((s, fwd_az, rev_az));
+        }
+    }
+    Err("geodesic::inverse failed to converge (near-antipodal)".to_string())
+}
+
+/**
+ * Solves the direct geodesic problem on the reference ellipsoid: given a
+ * starting point, a forward azimuth, and a distance along the geodesic it
+ * returns the destination point and the reverse azimuth.
+ *
+ * @param  lat1  Latitude of the starting point, radians
+ * @param  lon1  Longitude of the starting point, radians
+ * @param  az    Forward azimuth at the starting point, radians
+ * @param  s     Distance along the geodesic, same units as a
+ * @param  a     Ellipsoid semimajor axis
+ * @param  f     Ellipsoid flattening
+ *
+ * @return  (latitude, longitude, reverse azimuth) of the destination, the
+ *          reverse azimuth being the azimuth of the geodesic at the
+ *          destination in the direction of travel
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn direct(lat1: f64, lon1: f64, az: f64, s: f64,
+              a: f64, f: f64) -> (f64, f64, f64) {
+    let b = a*(1.0 - f);
+    let (sa, ca) = az.sin_cos();
+
+    let u1 = ((1.0 - f)*lat1.tan()).atan();
+    let (su1, cu1) = u1.sin_cos();
+    let sigma1 = su1.atan2(cu1*ca);
+    let sin_alpha = cu1*sa;
+    let cos2_alpha = 1.0 - sin_alpha*sin_alpha;
+    let u2sq = cos2_alpha*(a*a - b*b)/(b*b);
+    let big_a = 1.0 + u2sq/16384.0*
+        (4096.0 + u2sq*(-768.0 + u2sq*(320.0 - 175.0*u2sq)));
+    let big_b = u2sq/1024.0*
+        (256.0 + u2sq*(-128.0 + u2sq*(74.0 - 47.0*u2sq)));
+
+    let mut sigma = s/(b*big_a);
+    let mut cos_2sigma_m = (2.0*sigma1 + sigma).cos();
+    for _ in 0..200 {
+        cos_2sigma_m = (2.0*sigma1 + sigma).cos();
+        let (ss, cs) = sigma.sin_cos();
+        let delta_sigma = big_b*ss*(cos_2sigma_m +
+            0.25*big_b*(cs*(-1.0 + 2.0*cos_2sigma_m*cos_2sigma_m) -
+            big_b/6.0*cos_2sigma_m*(-3.0 + 4.0*ss*ss)*
+            (-3.0 + 4.0*cos_2sigma_m*cos_2sigma_m)));
+        let sigma_prev = sigma;
+        sigma = s/(b*big_a) + delta_sigma;
+        if (sigma - sigma_prev).abs() < 1.0e-12 {
+            break;
+        }
+    }
+
+    let (ss, cs) = sigma.sin_cos();
+    let lat2 = (su1*cs + cu1*ss*ca).atan2(
+        (1.0 - f)*(sin_alpha*sin_alpha +
+        (su1*ss - cu1*cs*ca).powi(2)).sqrt());
+    let lambda = (ss*sa).atan2(cu1*cs - su1*ss*ca);
+    let c = f/16.0*cos2_alpha*(4.0 + f*(4.0 - 3.0*cos2_alpha));
+    let big_l = lambda - (1.0 - c)*f*sin_alpha*
+        (sigma + c*ss*(cos_2sigma_m +
+         c*cs*(-1.0 + 2.0*cos_2sigma_m*cos_2sigma_m)));
+    let lon2 = lon1 + big_l;
+    let rev_az = sin_alpha.atan2(-su1*ss + cu1*cs*ca);
+
+    (lat2, lon2, rev_az)
+}
+
+/*
+ * Unit vector on the auxiliary sphere for a surface point at the given
+ * geodetic latitude and longitude, using the reduced (parametric)
+ * latitude beta = atan((1 - f)*tan(lat)).
+ */
+fn aux_site(lat: f64, lon: f64, f: f64) -> na::SMatrix<f64, 3, 1> {
+    let beta = ((1.0 - f)*lat.tan()).atan();
+    let (sb, cb) = beta.sin_cos();
+    let (sl, cl) = lon.sin_cos();
+    na::matrix![cb*cl ; cb*sl ; sb]
+}
+
+/*
+ * Normal to the great circle on the auxiliary sphere passing through the
+ * surface point (lat, lon) with the given forward azimuth.  Formed as the
+ * cross product of the site vector with the local travel direction,
+ * n = site x (sin(az)*east + cos(az)*north).
+ */
+fn aux_gc_normal(lat: f64, lon: f64, az: f64,
+                 f: f64) -> na::SMatrix<f64, 3, 1> {
+    let beta = ((1.0 - f)*lat.tan()).atan();
+    let (sb, cb) = beta.sin_cos();
+    let (sl, cl) = lon.sin_cos();
+    let site = na::matrix![cb*cl ; cb*sl ; sb];
+    let east = na::matrix![-sl ; cl ; 0.0];
+    let north = na::matrix![-sb*cl ; -sb*sl ; cb];
+    let (sa, ca) = az.sin_cos();
+    let dir = sa*east + ca*north;
+    site.cross(&dir)
+}
+
+/*
+ * Maps an auxiliary-sphere unit vector back to geodetic latitude and
+ * longitude on the spheroid.
+ */
+fn aux_to_geodetic(p: &na::SMatrix<f64, 3, 1>, f: f64) -> (f64, f64) {
+    let beta = p[2].asin();
+    let lat = (beta.tan()/(1.0 - f)).atan();
+    let lon = p[1].atan2(p[0]);
+    (lat, lon)
+}
+
+/// The intersection of two geodesics and its antipode, each a
+/// (latitude, longitude) pair.
+pub type IntersectionPair = ((f64, f64), (f64, f64));
+
+/**
+ * Returns the intersection of two geodesics on the reference ellipsoid,
+ * each specified by a starting surface point and a forward azimuth - the
+ * spheroidal analogue of the Sjoberg intersection method.  Both points
+ * are projected onto an auxiliary sphere via their reduced latitudes and
+ * the great-circle intersection is found as the cross product of the two
+ * great-circle normals.  The estimate is then refined on the spheroid
+ * with both launch azimuths held fixed: a Newton iteration on the arc
+ * lengths travelled along each geodesic (direct solver) closes the gap
+ * between the two advanced points (inverse solver), stopping once both
+ * arc length steps fall below 1.0e-6 or the gap below 1.0e-9.
+ *
+ * @param  lat1  Latitude of the first geodesic's start, radians
+ * @param  lon1  Longitude of the first geodesic's start, radians
+ * @param  az1   Forward azimuth of the first geodesic, radians
+ * @param  lat2  Latitude of the second geodesic's start, radians
+ * @param  lon2  Longitude of the second geodesic's start, radians
+ * @param  az2   Forward azimuth of the second geodesic, radians
+ * @param  a     Ellipsoid semimajor axis
+ * @param  f     Ellipsoid flattening
+ *
+ * @return  Ok:  ((lat, lon), (lat, lon)), the intersection nearest the
+ *               starting points followed by its geodetic antipode
+ *               (negated latitude, longitude plus pi)
+ *          Err: String when the geodesics are parallel or identical
+ *
+ * @author  Kurt Motekew  2025
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn intersect(lat1: f64, lon1: f64, az1: f64,
+                 lat2: f64, lon2: f64, az2: f64,
+                 a: f64, f: f64) -> Result<IntersectionPair, String> {
+    // Midpoint direction used to pick the nearer of the two antipodal
+    // great-circle solutions.
+    let mid = aux_site(lat1, lon1, f) + aux_site(lat2, lon2, f);
+
+    // Seed with the great-circle intersection on the auxiliary sphere.  The
+    // launch azimuths fix each great circle, so the normals never change;
+    // this leaves only the aux-sphere longitude distortion as error, which
+    // the spheroidal refinement below removes.
+    let n1 = aux_gc_normal(lat1, lon1, az1, f);
+    let n2 = aux_gc_normal(lat2, lon2, az2, f);
+    let cp = n1.cross(&n2);
+    if cp.norm() < 1.0e-14 {
+        return Err("geodesic::intersect parallel/identical \
+                    geodesics".to_string());
+    }
+    let p = cp.normalize();
+    let near = if p.dot(&mid) >= 0.0 { p } else { -p };
+    let (seed_lat, seed_lon) = aux_to_geodetic(&near, f);
+
+    // Refine the position (not the launch azimuths).  Each geodesic keeps
+    // its given azimuth; only the arc lengths (s1, s2) advanced along them
+    // vary.  Newton on (s1, s2) drives the two advanced points together:
+    // in the local tangent plane at the first point, moving ds1 along
+    // geodesic 1's heading and ds2 along geodesic 2's heading must close
+    // the residual vector separating the points.
+    let mut s1 = inverse(lat1, lon1, seed_lat, seed_lon, a, f)?.0;
+    let mut s2 = inverse(lat2, lon2, seed_lat, seed_lon, a, f)?.0;
+    let (mut lat, mut lon, _) = direct(lat1, lon1, az1, s1, a, f);
+    for _ in 0..100 {
+        // Forward headings where each geodesic currently sits
+        let (lat_a, lon_a, head1) = direct(lat1, lon1, az1, s1, a, f);
+        let (lat_b, lon_b, head2) = direct(lat2, lon2, az2, s2, a, f);
+        lat = lat_a;
+        lon = lon_a;
+
+        // Residual from point 1 to point 2 as a local east/north offset
+        let (dist, brg, _) = inverse(lat_a, lon_a, lat_b, lon_b, a, f)?;
+        if dist < 1.0e-9 {
+            break;
+        }
+        let (sb, cb) = brg.sin_cos();
+        let re = dist*sb;
+        let rn = dist*cb;
+
+        // Headings as east/north unit vectors
+        let (sa1, ca1) = head1.sin_cos();
+        let (sa2, ca2) = head2.sin_cos();
+        // Solve [t1 | -t2] [ds1; ds2] = r for the Newton step; the
+        // determinant is sin(head2 - head1), zero for parallel geodesics.
+        let det = sa2*ca1 - sa1*ca2;
+        if det.abs() < 1.0e-14 {
+            return Err("geodesic::intersect parallel/identical \
+                        geodesics".to_string());
+        }
+        let ds1 = (-re*ca2 + sa2*rn)/det;
+        let ds2 = (sa1*rn - re*ca1)/det;
+        s1 += ds1;
+        s2 += ds2;
+        if ds1.abs() < 1.0e-6  &&  ds2.abs() < 1.0e-6 {
+            let (latf, lonf, _) = direct(lat1, lon1, az1, s1, a, f);
+            lat = latf;
+            lon = lonf;
+            break;
+        }
+    }
+
+    let sol1 = (lat, lon);
+    let sol2 = aux_to_geodetic(&(-aux_site(lat, lon, f)), f);
+    Ok((sol1, sol2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Round trips the direct and inverse solvers on a WGS84 ellipsoid: the
+     * endpoint from the direct solver must reproduce the distance and
+     * forward azimuth when fed back through the inverse solver.
+     */
+    #[test]
+    fn direct_inverse_round_trip() {
+        let a = 6378137.0;
+        let f = 1.0/298.257223563;
+        let lat1 = 0.6;
+        let lon1 = 0.1;
+        let az = 1.0;
+        let s = 1.0e6;
+
+        let (lat2, lon2, _) = direct(lat1, lon1, az, s, a, f);
+        let (dist, fwd, _) = inverse(lat1, lon1, lat2, lon2, a, f)
+            .expect("inverse converges");
+
+        assert!((dist - s).abs() < 1.0e-4);
+        assert!((fwd - az).abs() < 1.0e-10);
+    }
+
+    /**
+     * Two geodesics constructed to emanate from a common point (found by
+     * walking outward from it with the direct solver) must intersect back
+     * at that point.
+     */
+    #[test]
+    fn geodesic_intersection() {
+        let a = 6378137.0;
+        let f = 1.0/298.257223563;
+        let latp = 0.3;
+        let lonp = 0.2;
+
+        // Walk outward from P along two azimuths; the geodesic that heads
+        // back toward P launches at the returned forward azimuth plus pi.
+        let pi = std::f64::consts::PI;
+        let (lat_a, lon_a, raz_a) = direct(latp, lonp, 0.5, 5.0e5, a, f);
+        let (lat_b, lon_b, raz_b) = direct(latp, lonp, 2.0, 5.0e5, a, f);
+
+        let ((lat, lon), _) = intersect(lat_a, lon_a, raz_a + pi,
+                                        lat_b, lon_b, raz_b + pi, a, f)
+            .expect("geodesics intersect");
+        assert!((lat - latp).abs() < 1.0e-9);
+        assert!((lon - lonp).abs() < 1.0e-9);
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * Copyright 2025 Kurt Motekew
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::f64::consts::{PI, FRAC_PI_2};
+
+/*
+ * The authalic area integral q(phi) used by the ellipsoidal quadrangle
+ * area.  The spherical (e -> 0) limit reduces to 2*sin(phi).
+ */
+fn q(phi: f64, e: f64) -> f64 {
+    let sp = phi.sin();
+    if e == 0.0 {
+        return 2.0*sp;
+    }
+    sp/(1.0 - e*e*sp*sp) + (1.0/(2.0*e))*((1.0 + e*sp)/(1.0 - e*sp)).ln()
+}
+
+/**
+ * Computes the true surface area of a latitude/longitude quadrangle on
+ * the oblate spheroid via the authalic formulation, matching the
+ * behaviour of octave-mapping's areaquad.  The area between two parallels
+ * and two meridians is (b^2/2)*|dlon|*|q(lat2) - q(lat1)| with
+ * b = a*sqrt(1 - e^2), dlon wrapped into [0, 2*pi] with an exact full
+ * revolution kept as 2*pi.
+ *
+ * @param  lat1  First bounding latitude, radians
+ * @param  lat2  Second bounding latitude, radians
+ * @param  lon1  First bounding longitude, radians
+ * @param  lon2  Second bounding longitude, radians
+ * @param  a     Ellipsoid semimajor axis
+ * @param  e     Ellipsoid eccentricity, 0 <= e < 1
+ *
+ * @return  Surface area of the quadrangle, units of a squared
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn areaquad(lat1: f64, lat2: f64, lon1: f64, lon2: f64,
+                a: f64, e: f64) -> f64 {
+    let b = a*(1.0 - e*e).sqrt();
+    // Wrap only spans that fall outside [0, 2*pi] so that an
+    // exact full revolution is preserved as 2*pi rather than reduced to 0.
+    let mut dlon = lon2 - lon1;
+    if !(0.0..=2.0*PI).contains(&dlon) {
+        dlon = dlon.rem_euclid(2.0*PI);
+    }
+    0.5*b*b*dlon*(q(lat2, e) - q(lat1, e)).abs()
+}
+
+/**
+ * Same quadrangle area as areaquad(), returned as a fraction of the
+ * total ellipsoid surface area.
+ *
+ * @param  lat1  First bounding latitude, radians
+ * @param  lat2  Second bounding latitude, radians
+ * @param  lon1  First bounding longitude, radians
+ * @param  lon2  Second bounding longitude, radians
+ * @param  a     Ellipsoid semimajor axis
+ * @param  e     Ellipsoid eccentricity, 0 <= e < 1
+ *
+ * @return  Quadrangle area as a fraction of the full ellipsoid surface
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn areaquad_fraction(lat1: f64, lat2: f64, lon1: f64, lon2: f64,
+                         a: f64, e: f64) -> f64 {
+    areaquad(lat1, lat2, lon1, lon2, a, e)/surface_area(a, e)
+}
+
+/**
+ * Total surface area of the oblate spheroid, the full-sphere authalic
+ * quadrangle spanning both poles and all longitudes.
+ *
+ * @param  a  Ellipsoid semimajor axis
+ * @param  e  Ellipsoid eccentricity, 0 <= e < 1
+ *
+ * @return  Total surface area, units of a squared
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn surface_area(a: f64, e: f64) -> f64 {
+    areaquad(-FRAC_PI_2, FRAC_PI_2, 0.0, 2.0*PI, a, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * A sphere's total area is 4*pi*a^2 and a full longitude band summed
+     * over complementary latitude cells recovers the whole surface.
+     */
+    #[test]
+    fn sphere_surface_area() {
+        let a = 6371000.0;
+        let total = surface_area(a, 0.0);
+        assert!((total - 4.0*PI*a*a).abs()/total < 1.0e-12);
+
+        let frac = areaquad_fraction(-FRAC_PI_2, FRAC_PI_2, 0.0, PI, a, 0.0);
+        assert!((frac - 0.5).abs() < 1.0e-12);
+    }
+}
@@ -0,0 +1,113 @@
+/*
+ * Copyright 2025 Kurt Motekew
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use nalgebra as na;
+
+/**
+ * Converts geodetic latitude, longitude, and height above the reference
+ * ellipsoid to Earth centered Earth fixed (ECEF) Cartesian coordinates.
+ * The ellipsoid is defined by its semimajor axis and eccentricity, the
+ * same parameters carried by OblateSpheroid.
+ *
+ * @param  lat  Geodetic latitude, radians
+ * @param  lon  Longitude, radians
+ * @param  h    Height above the ellipsoid surface, same units as a
+ * @param  a    Ellipsoid semimajor axis
+ * @param  e    Ellipsoid eccentricity, 0 <= e < 1
+ *
+ * @return  ECEF Cartesian coordinates
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn geodetic_to_ecef(lat: f64, lon: f64, h: f64,
+                        a: f64, e: f64) -> na::SMatrix<f64, 3, 1> {
+    let e2 = e*e;
+    let slat = lat.sin();
+    let clat = lat.cos();
+    let nrm = a/(1.0 - e2*slat*slat).sqrt();
+
+    na::matrix![(nrm + h)*clat*lon.cos() ;
+                (nrm + h)*clat*lon.sin() ;
+                (nrm*(1.0 - e2) + h)*slat]
+}
+
+/**
+ * Converts Earth centered Earth fixed (ECEF) Cartesian coordinates to
+ * geodetic latitude, longitude, and height above the reference ellipsoid.
+ * Longitude is closed form while latitude and height are recovered with
+ * the classic fixed-point iteration, converging to a fraction of a
+ * micro-arcsecond in a handful of passes.  The polar case (p ~ 0) is
+ * handled directly to avoid the division by cos(lat).
+ *
+ * @param  xyz  ECEF Cartesian coordinates
+ * @param  a    Ellipsoid semimajor axis
+ * @param  e    Ellipsoid eccentricity, 0 <= e < 1
+ *
+ * @return  (geodetic latitude, longitude, height), angles in radians
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn ecef_to_geodetic(xyz: &na::SMatrix<f64, 3, 1>,
+                        a: f64, e: f64) -> (f64, f64, f64) {
+    let e2 = e*e;
+    let lon = xyz[1].atan2(xyz[0]);
+    let p = (xyz[0]*xyz[0] + xyz[1]*xyz[1]).sqrt();
+
+    // On the polar axis the longitude tangent is degenerate - return the
+    // pole directly with height measured along the semiminor axis.
+    if p < a*1.0e-12 {
+        let b = a*(1.0 - e2).sqrt();
+        let lat = (std::f64::consts::FRAC_PI_2).copysign(xyz[2]);
+        return (lat, lon, xyz[2].abs() - b);
+    }
+
+    let tol = 1.0e-13;
+    let mut lat = xyz[2].atan2(p*(1.0 - e2));
+    loop {
+        let slat = lat.sin();
+        let nrm = a/(1.0 - e2*slat*slat).sqrt();
+        let h = p/lat.cos() - nrm;
+        let lat_new = xyz[2].atan2(p*(1.0 - e2*nrm/(nrm + h)));
+        if (lat_new - lat).abs() < tol {
+            lat = lat_new;
+            break;
+        }
+        lat = lat_new;
+    }
+
+    let slat = lat.sin();
+    let nrm = a/(1.0 - e2*slat*slat).sqrt();
+    let h = p/lat.cos() - nrm;
+
+    (lat, lon, h)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Round trips a geodetic coordinate through ECEF and back, using a
+     * WGS84-like ellipsoid.
+     */
+    #[test]
+    fn geodetic_round_trip() {
+        let a = 6378137.0;
+        let e = 0.081819190842622;
+        let lat = 0.6;
+        let lon = -1.2;
+        let h = 1500.0;
+
+        let xyz = geodetic_to_ecef(lat, lon, h, a, e);
+        let (lat2, lon2, h2) = ecef_to_geodetic(&xyz, a, e);
+
+        assert!((lat2 - lat).abs() < 1.0e-11);
+        assert!((lon2 - lon).abs() < 1.0e-11);
+        assert!((h2 - h).abs() < 1.0e-6);
+    }
+}
@@ -6,6 +6,13 @@ pub mod utl_const;
 // Models, algorithms, etc.
 pub mod oblate_spheroid;
 pub mod unit_circle;
+pub mod geodetic;
+pub mod conics;
+pub mod geodesic;
+pub mod ellipsoid;
+pub mod topocentric;
+pub mod separation;
+pub mod geodesy;
 
 // General utilities
 pub mod gp_plot;
@@ -10,6 +10,10 @@ use nalgebra as na;
 
 use crate::utl_const::DEG_PER_RAD;
 use crate::unit_circle;
+use crate::geodesic;
+use crate::geodetic;
+use crate::topocentric;
+use crate::geodesy;
 
 /**
  * Oblate spheroid definition (eccentricity and semimajor axis length)
@@ -164,6 +168,45 @@ impl TryFrom<&(f64, na::SMatrix<f64, 3, 1>)> for OblateSpheroid {
     }
 }
 
+impl TryFrom<&(f64, f64, f64, f64, f64)> for OblateSpheroid {
+    type Error = String;
+
+    /**
+     * Create OblateSpheroid and set coordinates given geodetic latitude,
+     * longitude, and height above the reference ellipsoid defined by the
+     * eccentricity and semimajor axis.  The stored semimajor axis is that
+     * of the spheroid of the same eccentricity passing through the
+     * resulting point; it equals the supplied one, and get_geodetic()
+     * returns the supplied latitude and height, only when height is zero.
+     *
+     * @param  osp  Oblate spheroidal parameters
+     *              .0  Eccentricity defining parameter, 0 <= eccen < 1
+     *              .1  Semimajor axis of the reference ellipsoid, smajor > 0
+     *              .2  Geodetic latitude, radians
+     *              .3  Longitude, radians
+     *              .4  Height above the ellipsoid surface
+     *
+     * @return  Ok:  OblateSpheroid
+     *          Err: String
+     */
+    fn try_from(osp: &(f64, f64, f64, f64, f64)) -> Result<Self, Self::Error> {
+        let (eccentricity, semimajor, latitude, longitude, height) = osp;
+
+        if *eccentricity < 0.0  ||  *eccentricity >= 1.0 {
+            return Err("Invalid Eccentricity: ".to_string() +
+                        &eccentricity.to_string());
+        } else if *semimajor < 0.0 {
+            return Err("Invalid Semimajor Axis: ".to_string() +
+                       &semimajor.to_string());
+        }
+
+        let mut os = OblateSpheroid::default();
+        os.set_with_geodetic(*eccentricity, *semimajor,
+                             *latitude, *longitude, *height);
+        Ok(os)
+    }
+}
+
 /*
  * Public immutable methods
  */
@@ -301,6 +344,244 @@ impl OblateSpheroid {
         aff[(2,2)] = self.get_semiminor();
         aff*to_3d*xyz
     }
+
+    /**
+     * @return  Flattening, f = 1 - sqrt(1 - e^2)
+     */
+    pub fn get_flattening(&self) -> f64 {
+        1.0 - (1.0 - self.ecc*self.ecc).sqrt()
+    }
+
+    /**
+     * Solves the inverse geodesic problem on this spheroid with Vincenty's
+     * iteration: the surface distance and forward/reverse azimuths between
+     * two geodetic locations.  See geodesic::inverse.
+     *
+     * @param  lat1  Geodetic latitude of the first point, radians
+     * @param  lon1  Longitude of the first point, radians
+     * @param  lat2  Geodetic latitude of the second point, radians
+     * @param  lon2  Longitude of the second point, radians
+     *
+     * @return  Ok:  (distance, forward azimuth, reverse azimuth)
+     *          Err: String when the near-antipodal solution fails
+     */
+    pub fn inverse_geodesic(&self, lat1: f64, lon1: f64,
+                            lat2: f64, lon2: f64)
+                                      -> Result<(f64, f64, f64), String> {
+        geodesic::inverse(lat1, lon1, lat2, lon2,
+                          self.sma, self.get_flattening())
+    }
+
+    /**
+     * Solves the direct geodesic problem on this spheroid: the destination
+     * point and reverse azimuth reached from a start point along a forward
+     * azimuth and distance.  See geodesic::direct.
+     *
+     * @param  lat1  Geodetic latitude of the starting point, radians
+     * @param  lon1  Longitude of the starting point, radians
+     * @param  az    Forward azimuth at the starting point, radians
+     * @param  s     Distance along the geodesic, same units as semimajor
+     *
+     * @return  (latitude, longitude, reverse azimuth) of the destination
+     */
+    pub fn direct_geodesic(&self, lat1: f64, lon1: f64,
+                           az: f64, s: f64) -> (f64, f64, f64) {
+        geodesic::direct(lat1, lon1, az, s, self.sma, self.get_flattening())
+    }
+
+    /**
+     * Geodetic coordinates of the current location with respect to the
+     * ellipsoid defined by this struct's eccentricity and semimajor axis.
+     * The conversion is the closed-form (non-iterative) ECEF to geodetic
+     * transformation using the reduced latitude; for surface points it is
+     * equivalent to recovering the geodetic latitude from the parametric
+     * latitude via tan(phi) = tan(beta)/(1 - f).
+     *
+     * @return  (geodetic latitude, longitude, height), angles in radians
+     */
+    pub fn get_geodetic(&self) -> (f64, f64, f64) {
+        let a = self.sma;
+        let b = self.get_semiminor();
+        let lon = self.xyz[1].atan2(self.xyz[0]);
+        let p = (self.xyz[0]*self.xyz[0] + self.xyz[1]*self.xyz[1]).sqrt();
+
+        // On the polar axis the reduced latitude is degenerate
+        if p < a*1.0e-12 {
+            let lat = (std::f64::consts::FRAC_PI_2).copysign(self.xyz[2]);
+            return (lat, lon, self.xyz[2].abs() - b);
+        }
+
+        let e2 = self.ecc*self.ecc;
+        let ep2 = (a*a - b*b)/(b*b);
+        // Reduced (parametric) latitude, then a single closed-form pass
+        let theta = (self.xyz[2]*a).atan2(p*b);
+        let (st, ct) = theta.sin_cos();
+        let lat = (self.xyz[2] + ep2*b*st*st*st).atan2(p - e2*a*ct*ct*ct);
+        let slat = lat.sin();
+        let nrm = a/(1.0 - e2*slat*slat).sqrt();
+        let h = p/lat.cos() - nrm;
+
+        (lat, lon, h)
+    }
+
+    /*
+     * Local orthonormal East-North-Up triad at the current location.  Up
+     * is the outward surface normal (the first contravariant basis
+     * vector, the gradient of the semimajor axis coordinate), east is
+     * the normalized longitude basis direction, and north
+     * completes the right handed set as up x east.
+     */
+    fn enu_triad(&self) -> (na::SMatrix<f64, 3, 1>,
+                            na::SMatrix<f64, 3, 1>,
+                            na::SMatrix<f64, 3, 1>) {
+        let (up, east, _) = self.get_cont_basis();
+        let up = na::Unit::new_normalize(up).into_inner();
+        let east = na::Unit::new_normalize(east).into_inner();
+        let north = up.cross(&east);
+        (east, north, up)
+    }
+
+    /**
+     * East-North-Up components of a target relative to this location.
+     *
+     * @param  target  Cartesian position of the target
+     *
+     * @return  (east, north, up) components
+     */
+    pub fn to_enu(&self, target: &na::SMatrix<f64, 3, 1>)
+                                            -> na::SMatrix<f64, 3, 1> {
+        let rel = target - self.xyz;
+        let (east, north, up) = self.enu_triad();
+        na::matrix![rel.dot(&east) ; rel.dot(&north) ; rel.dot(&up)]
+    }
+
+    /**
+     * North-East-Down components of a target relative to this location.
+     *
+     * @param  target  Cartesian position of the target
+     *
+     * @return  (north, east, down) components
+     */
+    pub fn to_ned(&self, target: &na::SMatrix<f64, 3, 1>)
+                                            -> na::SMatrix<f64, 3, 1> {
+        let enu = self.to_enu(target);
+        na::matrix![enu[1] ; enu[0] ; -enu[2]]
+    }
+
+    /**
+     * Azimuth, elevation, and range of a target relative to this location.
+     * Azimuth is measured clockwise from north.
+     *
+     * @param  target  Cartesian position of the target
+     *
+     * @return  (azimuth, elevation, range)
+     */
+    pub fn to_aer(&self, target: &na::SMatrix<f64, 3, 1>) -> (f64, f64, f64) {
+        topocentric::enu_to_aer(&self.to_enu(target))
+    }
+
+    /**
+     * Authalic (equal-area) latitude corresponding to a geodetic latitude
+     * on this spheroid, xi = asin(q(phi)/q(pi/2)) with the exact closed
+     * form of q.  The spherical (e -> 0) limit returns the geodetic
+     * latitude unchanged.
+     *
+     * @param  lat  Geodetic latitude, radians
+     *
+     * @return  Authalic latitude, radians
+     */
+    pub fn authalic_latitude(&self, lat: f64) -> f64 {
+        let e = self.ecc;
+        if e == 0.0 {
+            return lat;
+        }
+        let q = |phi: f64| {
+            let sp = phi.sin();
+            (1.0 - e*e)*(sp/(1.0 - e*e*sp*sp) -
+                (1.0/(2.0*e))*((1.0 - e*sp)/(1.0 + e*sp)).ln())
+        };
+        let ratio = q(lat)/q(std::f64::consts::FRAC_PI_2);
+        ratio.clamp(-1.0, 1.0).asin()
+    }
+
+    /**
+     * Surface area of a latitude/longitude quadrangle on this spheroid via
+     * the authalic formulation.  See geodesy::areaquad.
+     *
+     * @param  lat1  First bounding geodetic latitude, radians
+     * @param  lat2  Second bounding geodetic latitude, radians
+     * @param  lon1  First bounding longitude, radians
+     * @param  lon2  Second bounding longitude, radians
+     *
+     * @return  Quadrangle surface area, units of semimajor squared
+     */
+    pub fn quad_area(&self, lat1: f64, lat2: f64,
+                     lon1: f64, lon2: f64) -> f64 {
+        geodesy::areaquad(lat1, lat2, lon1, lon2, self.sma, self.ecc)
+    }
+
+    /**
+     * Total surface area of this spheroid.  See geodesy::surface_area.
+     *
+     * @return  Total surface area, units of semimajor squared
+     */
+    pub fn surface_area(&self) -> f64 {
+        geodesy::surface_area(self.sma, self.ecc)
+    }
+
+    /**
+     * Complete horizon limb (tangent cone) of the spheroid as seen from an
+     * external Cartesian observer - the closed curve of surface points
+     * where the line of sight grazes the surface.  The observer is mapped
+     * into the unit-sphere frame with the affine scaling diag(1/a,1/a,1/b),
+     * where the visible horizon is the exact circle at distance 1/|r| along
+     * r with radius sqrt(1 - 1/|r|^2).  That circle is sampled at n points
+     * and mapped back onto the spheroid with diag(a,a,b).
+     *
+     * @param  pos  Cartesian observer position, external to the spheroid
+     * @param  n    Number of points to sample around the limb
+     *
+     * @return  The limb points on the surface.  Empty when the observer is
+     *          on or within the spheroid (no horizon exists).
+     */
+    pub fn get_horizon_limb(&self, pos: &na::SMatrix<f64, 3, 1>,
+                            n: usize) -> Vec<na::SMatrix<f64, 3, 1>> {
+        let b = self.get_semiminor();
+        let inv = na::matrix![1.0/self.sma ; 1.0/self.sma ; 1.0/b];
+
+        // Observer in the unit-sphere frame
+        let r = na::matrix![pos[0]*inv[0] ; pos[1]*inv[1] ; pos[2]*inv[2]];
+        let rmag = r.norm();
+        // No horizon unless the observer is strictly outside the sphere
+        if rmag <= 1.0 {
+            return Vec::new();
+        }
+        let rhat = r/rmag;
+
+        // Horizon circle: center along rhat at distance 1/rmag, radius rho
+        let center = rhat/rmag;
+        let rho = (1.0 - 1.0/(rmag*rmag)).sqrt();
+
+        // Orthonormal basis spanning the horizon plane
+        let seed = if rhat[0].abs() < 0.9 {
+            na::matrix![1.0 ; 0.0 ; 0.0]
+        } else {
+            na::matrix![0.0 ; 1.0 ; 0.0]
+        };
+        let uhat = na::Unit::new_normalize(rhat.cross(&seed)).into_inner();
+        let vhat = rhat.cross(&uhat);
+
+        let mut limb = Vec::with_capacity(n);
+        for k in 0..n {
+            let theta = 2.0*std::f64::consts::PI*(k as f64)/(n as f64);
+            let pt = center + rho*(theta.cos()*uhat + theta.sin()*vhat);
+            // Map back onto the spheroid
+            limb.push(na::matrix![pt[0]*self.sma ;
+                                  pt[1]*self.sma ;
+                                  pt[2]*b]);
+        }
+        limb
+    }
 }
 
 /*
@@ -348,6 +629,24 @@ impl OblateSpheroid {
         self.lon = cart[1].atan2(cart[0]);
         self.lat = cart[2]/(self.sma*ome2.sqrt());
     }
+
+    /*
+     * Update coords given geodetic latitude, longitude, and height above
+     * the ellipsoid defined by the supplied eccentricity and semimajor
+     * axis.  The geodetic point is mapped to Cartesian and the oblate
+     * spheroidal coordinates are recovered from it.
+     *
+     * @param  eccen  Eccentricity defining parameter, 0 <= eccen < 1
+     * @param  smaj   Semimajor axis defining parameter, smajor > 0
+     * @param  lat    Geodetic latitude, radians
+     * @param  lon    Longitude, radians
+     * @param  h      Height above the ellipsoid surface
+     */
+    fn set_with_geodetic(&mut self, eccen: f64, smaj: f64,
+                         lat: f64, lon: f64, h: f64) {
+        let xyz = geodetic::geodetic_to_ecef(lat, lon, h, smaj, eccen);
+        self.set_with_cartesian(eccen, &xyz);
+    }
 }
 
 /*
@@ -396,5 +695,42 @@ mod tests {
         let rank = rank_2m.rank(eps);
         assert!(det < eps  &&  rank == 2);
     }
+
+    /**
+     * A surface point set from geodetic latitude/longitude is recovered by
+     * get_geodetic() on a WGS84-like ellipsoid, with zero height.
+     */
+    #[test]
+    fn geodetic_round_trip() {
+        let ecc = 0.081819190842622;
+        let smaj = 6378137.0;
+        let lat = 0.5;
+        let lon = 1.1;
+        let os = OblateSpheroid::try_from(&(ecc, smaj, lat, lon, 0.0))
+            .expect("Bad Oblate Spheroid ");
+        let (lat2, lon2, h2) = os.get_geodetic();
+        assert!((lat2 - lat).abs() < 1.0e-11);
+        assert!((lon2 - lon).abs() < 1.0e-11);
+        assert!(h2.abs() < 1.0e-6);
+    }
+
+    /**
+     * The spheroid surface area is non-zero and positive, and quadrangles
+     * tiling the surface sum back to the total - guards against the full
+     * revolution collapsing to zero area.
+     */
+    #[test]
+    fn spheroid_surface_area() {
+        let ecc = 0.081819190842622;
+        let smaj = 6378137.0;
+        let os = OblateSpheroid::try_from(&(ecc, smaj)).expect("Bad OS ");
+        let total = os.surface_area();
+        assert!(total > 0.0);
+
+        let half = os.quad_area(-std::f64::consts::FRAC_PI_2,
+                                 std::f64::consts::FRAC_PI_2,
+                                 0.0, std::f64::consts::PI);
+        assert!((half/total - 0.5).abs() < 1.0e-12);
+    }
 }
 
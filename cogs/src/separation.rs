@@ -0,0 +1,82 @@
+/*
+ * Copyright 2025 Kurt Motekew
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use nalgebra as na;
+
+/**
+ * A six element Cartesian state carrying a position and its velocity.
+ *
+ * @author  Kurt Motekew  2025
+ */
+#[derive(Copy, Clone)]
+pub struct State6 {
+    pub pos: na::SMatrix<f64, 3, 1>,
+    pub vel: na::SMatrix<f64, 3, 1>,
+}
+
+/**
+ * Returns the instantaneous rate of change of the angle between the two
+ * position vectors given their position/velocity states, analogous to
+ * SPICE's DVSEP.  The separation angle is theta = acos(u1.u2) with unit
+ * vectors u = r/|r|; its derivative is
+ * -(u1dot.u2 + u1.u2dot)/sqrt(1 - (u1.u2)^2) where
+ * udot = (v - (v.rhat)rhat)/|r|.  Parallel or antiparallel states (the
+ * singular denominator) and zero-length position vectors return 0.
+ *
+ * @param  s1  First position/velocity state
+ * @param  s2  Second position/velocity state
+ *
+ * @return  Time derivative of the separation angle, radians per time unit
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn dvsep(s1: &State6, s2: &State6) -> f64 {
+    let r1 = s1.pos.norm();
+    let r2 = s2.pos.norm();
+    if r1 == 0.0  ||  r2 == 0.0 {
+        return 0.0;
+    }
+
+    let u1 = s1.pos/r1;
+    let u2 = s2.pos/r2;
+    let u1dot = (s1.vel - s1.vel.dot(&u1)*u1)/r1;
+    let u2dot = (s2.vel - s2.vel.dot(&u2)*u2)/r2;
+
+    let c = u1.dot(&u2);
+    let denom = 1.0 - c*c;
+    if denom <= 0.0 {
+        return 0.0;
+    }
+
+    -(u1dot.dot(&u2) + u1.dot(&u2dot))/denom.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Two position vectors 90 degrees apart, the second moving away from
+     * the first at a known angular rate, should report that rate with an
+     * increasing separation (positive derivative).
+     */
+    #[test]
+    fn separation_rate() {
+        let s1 = State6 {
+            pos: na::matrix![1.0 ; 0.0 ; 0.0],
+            vel: na::matrix![0.0 ; 0.0 ; 0.0],
+        };
+        // On +y axis moving in -x: the angle opens at |v|/|r| = 2 rad/s
+        let s2 = State6 {
+            pos: na::matrix![0.0 ; 1.0 ; 0.0],
+            vel: na::matrix![-2.0 ; 0.0 ; 0.0],
+        };
+        let rate = dvsep(&s1, &s2);
+        assert!((rate - 2.0).abs() < 1.0e-12);
+    }
+}
@@ -0,0 +1,125 @@
+/*
+ * Copyright 2025 Kurt Motekew
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use nalgebra as na;
+
+/*
+ * Rotation carrying an ECEF vector into the local East-North-Up frame at
+ * the geodetic reference point (lat0, lon0).  The ENU->ECEF rotation is
+ * simply the transpose of this matrix.
+ */
+fn ecef_to_enu_rotation(lat0: f64, lon0: f64) -> na::SMatrix<f64, 3, 3> {
+    let (sp, cp) = lat0.sin_cos();
+    let (sl, cl) = lon0.sin_cos();
+    na::matrix![   -sl ,     cl , 0.0 ;
+                -sp*cl , -sp*sl ,  cp ;
+                 cp*cl ,  cp*sl ,  sp]
+}
+
+/**
+ * Rotates a vector expressed in the local East-North-Up frame at the
+ * reference geodetic point into the ECEF frame.
+ *
+ * @param  enu   East-North-Up vector
+ * @param  lat0  Geodetic latitude of the reference point, radians
+ * @param  lon0  Longitude of the reference point, radians
+ *
+ * @return  The same vector expressed in ECEF axes
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn enu_to_ecef(enu: &na::SMatrix<f64, 3, 1>,
+                   lat0: f64, lon0: f64) -> na::SMatrix<f64, 3, 1> {
+    ecef_to_enu_rotation(lat0, lon0).transpose()*enu
+}
+
+/**
+ * Converts an ECEF position to the local East-North-Up components
+ * relative to a reference geodetic point.
+ *
+ * @param  xyz       ECEF position of the target
+ * @param  ref_ecef  ECEF position of the reference point
+ * @param  lat0      Geodetic latitude of the reference point, radians
+ * @param  lon0      Longitude of the reference point, radians
+ *
+ * @return  East-North-Up components of the target relative to the point
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn ecef_to_enu(xyz: &na::SMatrix<f64, 3, 1>,
+                   ref_ecef: &na::SMatrix<f64, 3, 1>,
+                   lat0: f64, lon0: f64) -> na::SMatrix<f64, 3, 1> {
+    ecef_to_enu_rotation(lat0, lon0)*(xyz - ref_ecef)
+}
+
+/**
+ * Converts azimuth, elevation, and range to local East-North-Up
+ * components.  Azimuth is measured clockwise from north.
+ *
+ * @param  az     Azimuth, radians
+ * @param  el     Elevation, radians
+ * @param  range  Slant range, same units as the returned vector
+ *
+ * @return  East-North-Up components
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn aer_to_enu(az: f64, el: f64, range: f64) -> na::SMatrix<f64, 3, 1> {
+    let (sel, cel) = el.sin_cos();
+    let (saz, caz) = az.sin_cos();
+    na::matrix![range*cel*saz ;
+                range*cel*caz ;
+                range*sel]
+}
+
+/**
+ * Converts local East-North-Up components to azimuth, elevation, and
+ * range.  Azimuth is measured clockwise from north.
+ *
+ * @param  enu  East-North-Up components
+ *
+ * @return  (azimuth, elevation, range)
+ *
+ * @author  Kurt Motekew  2025
+ */
+pub fn enu_to_aer(enu: &na::SMatrix<f64, 3, 1>) -> (f64, f64, f64) {
+    let range = enu.norm();
+    let az = enu[0].atan2(enu[1]);
+    let el = if range > 0.0 {
+        (enu[2]/range).asin()
+    } else {
+        0.0
+    };
+    (az, el, range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Round trips azimuth/elevation/range through ENU, then ENU through
+     * ECEF relative to a reference point.
+     */
+    #[test]
+    fn topocentric_round_trip() {
+        let lat0 = 0.6;
+        let lon0 = -1.2;
+        let ref_ecef = na::matrix![4.0e6 ; -3.0e6 ; 3.6e6];
+
+        let enu = aer_to_enu(0.7, 0.3, 1.0e5);
+        let (az, el, range) = enu_to_aer(&enu);
+        assert!((az - 0.7).abs() < 1.0e-12);
+        assert!((el - 0.3).abs() < 1.0e-12);
+        assert!((range - 1.0e5).abs() < 1.0e-6);
+
+        let xyz = enu_to_ecef(&enu, lat0, lon0) + ref_ecef;
+        let enu2 = ecef_to_enu(&xyz, &ref_ecef, lat0, lon0);
+        assert!((enu2 - enu).norm() < 1.0e-6);
+    }
+}
@@ -56,6 +56,38 @@ pub fn tangent(pos: &na::SMatrix<f64, 2, 1>,
     }
 }
 
+/*
+ * Computes the intersection point on a unit circle given a location
+ * and pointing vector from that location.  Returns None when the
+ * pointing vector misses the circle.
+ *
+ * @param  pos  Position external to circle, origin of pointing vector
+ * @param  pnt  Pointing vector
+ *
+ * @return   Some(location of intersection on the circle) when the
+ *           pointing vector intersects, otherwise None
+ *
+ * @author  Kurt Motekew  2022/01/27  Initial, C++ version
+ * @author  Kurt Motekew  2025        Rust version
+ */
+pub fn intersect(pos: &na::SMatrix<f64, 2, 1>,
+                 pnt: &na::SMatrix<f64, 2, 1>)
+                                      -> Option<na::SMatrix<f64, 2, 1>> {
+    let pnt_hat = pnt.normalize();
+
+    let alpha = pnt_hat.dot(&pnt_hat);
+    let beta = pos.dot(&pnt_hat);
+    let gamma = pos.dot(pos);
+
+    let d = beta*beta - alpha*(gamma - 1.0);
+    if d >= 0.0 {
+        let s = -(beta + d.sqrt())/alpha;
+        Some(pos + s*pnt_hat)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,45 +101,18 @@ mod tests {
         let err = (tp - tpt).norm();
         assert!(err < 1.0e-10);
     }
-}
 
-/*
- * Computes the intersection point on a unit circle given a location
- * and pointing vector from that location.  Make sure to use with the
- * NoSolutionException that will be thrown if the pointing vector misses
- * the circle.
- *
- * @tparam  T  Data type
- *
- * @param  pos  Position external to circle, origin of pointing vector
- * @param  pnt  Pointing vector
- *
- * @return   Location of intersection on the circle
- *
- * @throws  NoSolutionException When the pointing vector does not
- *                              intersect the circle.
- *
- * @author  Kurt Motekew  2022/01/27
- */
-/*
-template<typename T>
-Eigen::Matrix<T, 2, 1> intersect(const Eigen::Matrix<T, 2, 1>& pos,
-                                 const Eigen::Matrix<T, 2, 1>& pnt)
-{
-  Eigen::Matrix<T, 2, 1> pnt_hat {pnt.normalized()};
-
-  T alpha {pnt_hat(0)*pnt_hat(0) + pnt_hat(1)*pnt_hat(1)};
-  T beta {pos(0)*pnt_hat(0) + pos(1)*pnt_hat(1)};
-  T gamma {pos(0)*pos(0) + pos(1)*pos(1)};
+    #[test]
+    fn circle_intersect() {
+        // Ray from +x axis back toward the origin hits the near side
+        let pos = na::matrix![3.0 ; 0.0];
+        let pnt = na::matrix![-1.0 ; 0.0];
+        let xy = intersect(&pos, &pnt).expect("ray hits circle");
+        let xyt = na::matrix![1.0 ; 0.0];
+        assert!((xy - xyt).norm() < 1.0e-12);
 
-  T zero {static_cast<T>(0)};
-  T one {static_cast<T>(1)};
-  T d {beta*beta - alpha*(gamma - one)};
-  if (d >= zero) {
-    T s = -(beta + std::sqrt(d))/alpha;
-    return pos + s*pnt_hat;
-  } else {
-    throw NoSolutionException("unit_circle::intersect");
-  }
+        // A miss returns None
+        let pnt_miss = na::matrix![0.0 ; 1.0];
+        assert!(intersect(&pos, &pnt_miss).is_none());
+    }
 }
-*/